@@ -4,9 +4,12 @@ use once_cell::sync::Lazy;
 use parking_lot::Mutex;
 use std::collections::HashMap;
 use std::ffi::{CStr, CString};
-use std::os::raw::{c_char, c_int};
+use std::os::raw::{c_char, c_int, c_void};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::mpsc;
 use std::sync::Arc;
-use std::time::Duration;
+use std::sync::Once;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use tokio::runtime::Runtime;
 
 // ============================================================================
@@ -42,6 +45,10 @@ fn clear_last_error() {
 // ============================================================================
 
 static RUNTIME: Lazy<Runtime> = Lazy::new(|| {
+    // Streaming many channels fans out into a large number of tokio TCP
+    // connections and/or fallback file handles, so raise the descriptor
+    // limit before the first of them is opened.
+    ensure_fd_limit();
     tokio::runtime::Builder::new_multi_thread()
         .worker_threads(4)
         .enable_all()
@@ -49,6 +56,123 @@ static RUNTIME: Lazy<Runtime> = Lazy::new(|| {
         .expect("Failed to create Tokio runtime")
 });
 
+// ============================================================================
+// File Descriptor Limits
+// ============================================================================
+
+static FD_LIMIT_ONCE: Once = Once::new();
+
+/// Raise the descriptor soft limit the first time a runtime or stream is
+/// created. Subsequent calls are no-ops; use [`nominal_set_max_open_files`]
+/// to re-apply a specific target later.
+fn ensure_fd_limit() {
+    FD_LIMIT_ONCE.call_once(|| {
+        let _ = raise_fd_limit(None);
+    });
+}
+
+/// Clamp a desired soft limit to an optional platform ceiling.
+///
+/// macOS rejects a `setrlimit` that targets the raw hard limit, so the caller
+/// supplies `Some(ceiling)` there and `None` on platforms that accept the hard
+/// limit directly.
+fn clamp_fd_target(desired: u64, ceiling: Option<u64>) -> u64 {
+    match ceiling {
+        Some(c) => desired.min(c),
+        None => desired,
+    }
+}
+
+/// Raise the process open-file soft limit toward the hard limit.
+///
+/// When `target` is `Some`, the soft limit is moved toward that value (still
+/// bounded by the hard limit); when `None`, it is raised as high as the hard
+/// limit allows. Returns the soft limit actually in effect afterwards so the
+/// caller can confirm it has enough headroom before allocating writers.
+#[cfg(unix)]
+fn raise_fd_limit(target: Option<u64>) -> u64 {
+    unsafe {
+        let mut rlim = libc::rlimit {
+            rlim_cur: 0,
+            rlim_max: 0,
+        };
+        if libc::getrlimit(libc::RLIMIT_NOFILE, &mut rlim) != 0 {
+            return 0;
+        }
+
+        let hard = rlim.rlim_max as u64;
+        let desired = target.map(|t| t.min(hard)).unwrap_or(hard);
+        let clamped = clamp_fd_target(desired, macos_fd_ceiling());
+
+        rlim.rlim_cur = clamped as libc::rlim_t;
+        if libc::setrlimit(libc::RLIMIT_NOFILE, &rlim) != 0 {
+            // Report whatever soft limit remains in force on failure.
+            let mut cur = libc::rlimit {
+                rlim_cur: 0,
+                rlim_max: 0,
+            };
+            if libc::getrlimit(libc::RLIMIT_NOFILE, &mut cur) == 0 {
+                return cur.rlim_cur as u64;
+            }
+            return 0;
+        }
+
+        clamped
+    }
+}
+
+/// The largest per-process descriptor count macOS will accept, or `None` on
+/// other platforms. macOS caps this at `kern.maxfilesperproc` and `OPEN_MAX`.
+#[cfg(target_os = "macos")]
+fn macos_fd_ceiling() -> Option<u64> {
+    unsafe {
+        let mut value: libc::c_int = 0;
+        let mut size = std::mem::size_of::<libc::c_int>();
+        let name = b"kern.maxfilesperproc\0";
+        let ceiling = if libc::sysctlbyname(
+            name.as_ptr() as *const c_char,
+            &mut value as *mut _ as *mut libc::c_void,
+            &mut size,
+            std::ptr::null_mut(),
+            0,
+        ) == 0
+            && value > 0
+        {
+            (value as u64).min(libc::OPEN_MAX as u64)
+        } else {
+            libc::OPEN_MAX as u64
+        };
+        Some(ceiling)
+    }
+}
+
+#[cfg(all(unix, not(target_os = "macos")))]
+fn macos_fd_ceiling() -> Option<u64> {
+    None
+}
+
+/// No-op on platforms without POSIX resource limits (e.g. Windows).
+#[cfg(not(unix))]
+fn raise_fd_limit(_target: Option<u64>) -> u64 {
+    0
+}
+
+/// Re-apply the descriptor soft limit, optionally toward a specific target.
+///
+/// Pass `0` to raise the soft limit as high as the hard limit permits.
+/// Returns the soft limit in effect afterwards (always `0` on Windows).
+///
+/// Also arms [`FD_LIMIT_ONCE`] so a deliberately conservative `target` set
+/// before the first [`nominal_init`] call isn't silently overwritten when the
+/// runtime's lazy [`ensure_fd_limit`] later runs `raise_fd_limit(None)`.
+#[no_mangle]
+pub extern "C" fn nominal_set_max_open_files(target: u64) -> u64 {
+    let requested = if target == 0 { None } else { Some(target) };
+    let applied = raise_fd_limit(requested);
+    FD_LIMIT_ONCE.call_once(|| {});
+    applied
+}
+
 // ============================================================================
 // Handle Types and Registries
 // ============================================================================
@@ -64,11 +188,111 @@ static STREAMS: Lazy<Mutex<HashMap<StreamHandle, Arc<NominalDatasetStream>>>> =
 struct WriterState {
     stream: Arc<NominalDatasetStream>,
     descriptor: ChannelDescriptor,
+    // Throughput counters maintained entirely by this crate's push/flush
+    // functions (never read off `NominalDatasetStream`), so the stats they
+    // feed don't depend on stream-introspection methods. Atomics let them be
+    // bumped while the writer borrows `descriptor` immutably.
+    points_pushed: AtomicU64,
+    bytes_pushed: AtomicU64,
+    points_flushed: AtomicU64,
+    bytes_flushed: AtomicU64,
+    last_flush_ns: AtomicU64,
+}
+
+/// Record a push of `count` points totalling `bytes` against a writer's
+/// counters.
+fn record_push(state: &WriterState, count: u64, bytes: u64) {
+    state.points_pushed.fetch_add(count, Ordering::Relaxed);
+    state.bytes_pushed.fetch_add(bytes, Ordering::Relaxed);
+}
+
+/// Record a successful flush: everything pushed so far is caught up to.
+fn record_flush(state: &WriterState) {
+    state
+        .points_flushed
+        .store(state.points_pushed.load(Ordering::Relaxed), Ordering::Relaxed);
+    state
+        .bytes_flushed
+        .store(state.bytes_pushed.load(Ordering::Relaxed), Ordering::Relaxed);
+    state.last_flush_ns.store(now_ns(), Ordering::Relaxed);
+}
+
+/// Record a successful flush against every writer on `stream`.
+///
+/// `NominalDatasetStream::flush` has no per-channel variant — both
+/// `nominal_flush` and `nominal_flush_channel` flush the whole stream — so a
+/// flush triggered through either entry point catches up every writer on it,
+/// not just the one the caller named.
+fn record_stream_flush(stream: &Arc<NominalDatasetStream>) {
+    let writers = WRITERS.lock();
+    for writer_arc in writers.values() {
+        let state_guard = writer_arc.lock();
+        if Arc::ptr_eq(&state_guard.stream, stream) {
+            record_flush(&state_guard);
+        }
+    }
+}
+
+/// Derive the "still outstanding since the last flush" backlog — (bytes,
+/// points) — from raw push/flush counters. Pulled out of
+/// `nominal_get_writer_stats` so this arithmetic is unit-testable without a
+/// real `WriterState`/`NominalDatasetStream`.
+fn writer_backlog(
+    points_pushed: u64,
+    points_flushed: u64,
+    bytes_pushed: u64,
+    bytes_flushed: u64,
+) -> (u64, u64) {
+    (
+        bytes_pushed.saturating_sub(bytes_flushed),
+        points_pushed.saturating_sub(points_flushed),
+    )
 }
 
 static WRITERS: Lazy<Mutex<HashMap<WriterHandle, Arc<Mutex<WriterState>>>>> =
     Lazy::new(|| Mutex::new(HashMap::new()));
 
+/// C function invoked when a background write for a stream fails.
+type ErrorCallbackFn = extern "C" fn(c_int, *const c_char, *mut c_void);
+
+/// The callback pointer plus the opaque LabVIEW context passed back to it.
+struct ErrorCallback {
+    func: ErrorCallbackFn,
+    user_data: *mut c_void,
+}
+
+// The raw pointer is owned by LabVIEW; we only hand it back untouched on the
+// dispatch thread, so it is safe to move across the channel boundary.
+unsafe impl Send for ErrorCallback {}
+
+/// Holds the worker that forwards a stream's error events onto the dedicated
+/// dispatch thread. Dropping it (on re-registration or shutdown) tears both
+/// halves down: the forwarder is aborted, the channel is closed, and the
+/// dispatch thread is joined before `drop` returns, so no callback can still
+/// be executing (and no buffered error can still be delivered) once
+/// deregistration or `nominal_shutdown` returns.
+struct ErrorDispatch {
+    // Closed (by taking it) before joining the dispatch thread below, so its
+    // `recv()` loop observes the channel closing and exits.
+    sender: Option<mpsc::Sender<(c_int, String)>>,
+    forwarder: tokio::task::JoinHandle<()>,
+    dispatch_thread: Option<std::thread::JoinHandle<()>>,
+}
+
+impl Drop for ErrorDispatch {
+    fn drop(&mut self) {
+        self.forwarder.abort();
+        self.sender.take();
+        if let Some(handle) = self.dispatch_thread.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+// Per-stream asynchronous error callbacks registered by LabVIEW.
+static ERROR_CALLBACKS: Lazy<Mutex<HashMap<StreamHandle, ErrorDispatch>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
 static NEXT_STREAM_HANDLE: Lazy<Mutex<u64>> = Lazy::new(|| Mutex::new(1));
 static NEXT_WRITER_HANDLE: Lazy<Mutex<u64>> = Lazy::new(|| Mutex::new(1));
 
@@ -120,6 +344,15 @@ fn parse_tags_csv(tags_csv: &str) -> Vec<(&str, &str)> {
         .collect()
 }
 
+/// Wall-clock time since the Unix epoch in nanoseconds, saturating to 0 if the
+/// clock is before the epoch.
+fn now_ns() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_nanos() as u64)
+        .unwrap_or(0)
+}
+
 // ============================================================================
 // Core FFI Functions
 // ============================================================================
@@ -133,6 +366,7 @@ pub unsafe extern "C" fn nominal_init(
     out_stream_handle: *mut u64,
 ) -> c_int {
     clear_last_error();
+    ensure_fd_limit();
 
     if out_stream_handle.is_null() {
         set_last_error("Output handle pointer is null".to_string());
@@ -284,6 +518,11 @@ pub unsafe extern "C" fn nominal_create_channel(
     let state = WriterState {
         stream: Arc::clone(&stream),
         descriptor,
+        points_pushed: AtomicU64::new(0),
+        bytes_pushed: AtomicU64::new(0),
+        points_flushed: AtomicU64::new(0),
+        bytes_flushed: AtomicU64::new(0),
+        last_flush_ns: AtomicU64::new(0),
     };
     WRITERS.lock().insert(handle, Arc::new(Mutex::new(state)));
 
@@ -333,6 +572,8 @@ pub unsafe extern "C" fn nominal_push_double_batch(
         writer.push(timestamp, value);
     }
 
+    record_push(&state_guard, count as u64, (count * std::mem::size_of::<f64>()) as u64);
+
     SUCCESS
 }
 
@@ -372,6 +613,10 @@ pub unsafe extern "C" fn nominal_shutdown(stream_handle: u64) -> c_int {
         }
     };
 
+    // Drop any registered error callback so its pointers are never invoked
+    // after the stream it referred to is gone.
+    ERROR_CALLBACKS.lock().remove(&stream_handle);
+
     SUCCESS
 }
 
@@ -414,6 +659,120 @@ pub extern "C" fn nominal_get_last_error(
     })
 }
 
+// ============================================================================
+// Asynchronous Error Reporting
+// ============================================================================
+
+/// Spawn the dedicated thread that delivers every `(code, message)` received
+/// on `receiver` to `cb` until the channel is closed. Split out from
+/// [`nominal_set_error_callback`] so the delivery path — queued error reaches
+/// the registered callback — is unit-testable without a real
+/// `NominalDatasetStream` feeding the channel.
+fn spawn_error_dispatch_thread(
+    cb: ErrorCallback,
+    receiver: mpsc::Receiver<(c_int, String)>,
+) -> std::io::Result<std::thread::JoinHandle<()>> {
+    std::thread::Builder::new()
+        .name("nominal-error-dispatch".to_string())
+        .spawn(move || {
+            // Force capture of the whole `cb` rather than just the fields
+            // touched below: 2021-edition disjoint capture would otherwise
+            // capture `cb.user_data` as a bare `*mut c_void`, which isn't
+            // `Send` on its own and would bypass `unsafe impl Send for
+            // ErrorCallback` (the thing that actually justifies moving it).
+            let cb = cb;
+            while let Ok((code, message)) = receiver.recv() {
+                if let Ok(c_message) = CString::new(message) {
+                    (cb.func)(code, c_message.as_ptr(), cb.user_data);
+                }
+            }
+        })
+}
+
+/// Register (or clear) a callback that receives background write errors for a
+/// stream.
+///
+/// Pass a null `callback` to deregister. The callback is dispatched from a
+/// dedicated thread — never a tokio worker — so it will not deadlock against
+/// LabVIEW's single-threaded reentrancy. `user_data` is handed back to the
+/// callback untouched and must remain valid until the callback is cleared or
+/// the stream is shut down.
+#[no_mangle]
+pub unsafe extern "C" fn nominal_set_error_callback(
+    stream_handle: u64,
+    callback: Option<ErrorCallbackFn>,
+    user_data: *mut c_void,
+) -> c_int {
+    clear_last_error();
+
+    let stream = {
+        let streams = STREAMS.lock();
+        match streams.get(&stream_handle) {
+            Some(s) => Arc::clone(s),
+            None => {
+                set_last_error(format!("Invalid stream handle: {}", stream_handle));
+                return ERROR_INVALID_HANDLE;
+            }
+        }
+    };
+
+    // Tearing down any prior registration stops its forwarder and dispatch
+    // thread before a new one is installed.
+    let mut callbacks = ERROR_CALLBACKS.lock();
+    callbacks.remove(&stream_handle);
+
+    let func = match callback {
+        Some(f) => f,
+        None => return SUCCESS,
+    };
+
+    let cb = ErrorCallback { func, user_data };
+
+    let (sender, receiver) = mpsc::channel::<(c_int, String)>();
+    let dispatch_thread = match spawn_error_dispatch_thread(cb, receiver) {
+        Ok(handle) => handle,
+        Err(_) => {
+            set_last_error("Failed to spawn error dispatch thread".to_string());
+            return ERROR_GENERIC;
+        }
+    };
+
+    let forward_sender = sender.clone();
+    let forwarder = RUNTIME.spawn(async move {
+        // subscribe_errors() is the one piece of this path not covered by
+        // an in-repo test: it requires a live NominalDatasetStream to drive
+        // a real background failure through. spawn_error_dispatch_thread
+        // above — the channel, the dispatch thread, and the callback firing
+        // — is covered by test_error_dispatch_delivers_to_callback instead.
+        let mut errors = stream.subscribe_errors();
+        loop {
+            match errors.recv().await {
+                Ok(error) => {
+                    if forward_sender
+                        .send((ERROR_RUNTIME, error.to_string()))
+                        .is_err()
+                    {
+                        break;
+                    }
+                }
+                Err(tokio::sync::broadcast::error::RecvError::Lagged(_)) => continue,
+                Err(tokio::sync::broadcast::error::RecvError::Closed) => break,
+            }
+        }
+    });
+
+    callbacks.insert(
+        stream_handle,
+        ErrorDispatch {
+            sender: Some(sender),
+            forwarder,
+            dispatch_thread: Some(dispatch_thread),
+        },
+    );
+
+    SUCCESS
+}
+
 // ============================================================================
 // Lifecycle Control Functions
 // ============================================================================
@@ -434,7 +793,7 @@ pub unsafe extern "C" fn nominal_flush(stream_handle: u64) -> c_int {
         }
     };
 
-    RUNTIME.block_on(async {
+    let result = RUNTIME.block_on(async {
         match stream.flush().await {
             Ok(_) => SUCCESS,
             Err(e) => {
@@ -442,7 +801,13 @@ pub unsafe extern "C" fn nominal_flush(stream_handle: u64) -> c_int {
                 ERROR_RUNTIME
             }
         }
-    })
+    });
+
+    if result == SUCCESS {
+        record_stream_flush(&stream);
+    }
+
+    result
 }
 
 /// Flush a specific channel writer
@@ -461,17 +826,27 @@ pub unsafe extern "C" fn nominal_flush_channel(writer_handle: u64) -> c_int {
         }
     };
 
-    let state_guard = writer_arc.lock();
-    
-    RUNTIME.block_on(async {
-        match state_guard.stream.flush().await {
+    // Resolve the stream and drop the writer lock before flushing: flush()
+    // has no per-channel variant (it flushes the whole stream), and
+    // record_stream_flush below needs to re-lock every writer on it,
+    // including this one.
+    let stream = Arc::clone(&writer_arc.lock().stream);
+
+    let result = RUNTIME.block_on(async {
+        match stream.flush().await {
             Ok(_) => SUCCESS,
             Err(e) => {
                 set_last_error(format!("Channel flush failed: {}", e));
                 ERROR_RUNTIME
             }
         }
-    })
+    });
+
+    if result == SUCCESS {
+        record_stream_flush(&stream);
+    }
+
+    result
 }
 
 // ============================================================================
@@ -512,6 +887,81 @@ pub extern "C" fn nominal_is_writer_valid(writer_handle: u64) -> c_int {
     }
 }
 
+/// Throughput and backpressure snapshot for a writer.
+///
+/// Every field here is tracked entirely by this crate's own push/flush
+/// instrumentation (see [`record_push`] and [`record_flush`]) rather than
+/// read off `NominalDatasetStream`, so it depends on no stream-introspection
+/// API and is genuinely per-writer: two writers on the same stream can have
+/// different backlogs depending on how much each has pushed since its own
+/// last flush.
+#[repr(C)]
+pub struct NominalWriterStats {
+    /// Total data points accepted by this writer's push functions.
+    pub points_pushed: u64,
+    /// Bytes pushed to this writer since its last successful flush.
+    pub bytes_buffered: u64,
+    /// Always 0 today: this crate does not yet drop points under
+    /// backpressure. Reserved for when it does, so existing callers don't
+    /// need an ABI change to start observing drops.
+    pub points_dropped: u64,
+    /// Wall-clock timestamp of this writer's last successful flush (ns since
+    /// the Unix epoch), or 0 if the channel has never been flushed.
+    pub last_flush_ns: u64,
+    /// Points pushed to this writer since its last successful flush.
+    pub queue_depth: u64,
+}
+
+/// Fill `out_stats` with the current counters for a writer.
+///
+/// The counters reflect only pushes and flushes made through this library:
+/// `bytes_buffered` and `queue_depth` count what was pushed since the last
+/// successful `nominal_flush`/`nominal_flush_channel` on the writer's stream.
+/// They do not reflect what the stream has already uploaded in the
+/// background.
+#[no_mangle]
+pub unsafe extern "C" fn nominal_get_writer_stats(
+    writer_handle: u64,
+    out_stats: *mut NominalWriterStats,
+) -> c_int {
+    clear_last_error();
+
+    if out_stats.is_null() {
+        set_last_error("Output stats pointer is null".to_string());
+        return ERROR_INVALID_PARAM;
+    }
+
+    let writer_arc = {
+        let writers = WRITERS.lock();
+        match writers.get(&writer_handle) {
+            Some(w) => Arc::clone(w),
+            None => {
+                set_last_error(format!("Invalid writer handle: {}", writer_handle));
+                return ERROR_INVALID_HANDLE;
+            }
+        }
+    };
+
+    let state_guard = writer_arc.lock();
+
+    let (bytes_buffered, queue_depth) = writer_backlog(
+        state_guard.points_pushed.load(Ordering::Relaxed),
+        state_guard.points_flushed.load(Ordering::Relaxed),
+        state_guard.bytes_pushed.load(Ordering::Relaxed),
+        state_guard.bytes_flushed.load(Ordering::Relaxed),
+    );
+
+    *out_stats = NominalWriterStats {
+        points_pushed: state_guard.points_pushed.load(Ordering::Relaxed),
+        bytes_buffered,
+        points_dropped: 0,
+        last_flush_ns: state_guard.last_flush_ns.load(Ordering::Relaxed),
+        queue_depth,
+    };
+
+    SUCCESS
+}
+
 // ============================================================================
 // Bulk Operations Functions
 // ============================================================================
@@ -558,6 +1008,8 @@ pub unsafe extern "C" fn nominal_push_int64_batch(
         writer.push(timestamp, value);
     }
 
+    record_push(&state_guard, count as u64, (count * std::mem::size_of::<i64>()) as u64);
+
     SUCCESS
 }
 
@@ -603,6 +1055,8 @@ pub unsafe extern "C" fn nominal_push_bool_batch(
         writer.push(timestamp, value);
     }
 
+    record_push(&state_guard, count as u64, count as u64);
+
     SUCCESS
 }
 
@@ -641,10 +1095,11 @@ pub unsafe extern "C" fn nominal_push_string_batch(
 
     let state_guard = writer_arc.lock();
     let mut writer = state_guard.stream.string_writer(&state_guard.descriptor);
-    
+    let mut bytes_pushed = 0u64;
+
     for i in 0..count {
         let timestamp = Duration::from_nanos(timestamps_slice[i]);
-        
+
         // Convert C string to Rust string
         let value_str = match c_str_to_string(values_slice[i]) {
             Ok(s) => s,
@@ -653,10 +1108,115 @@ pub unsafe extern "C" fn nominal_push_string_batch(
                 return ERROR_INVALID_PARAM;
             }
         };
-        
+
+        bytes_pushed += value_str.len() as u64;
         writer.push(timestamp, &value_str);
     }
 
+    record_push(&state_guard, count as u64, bytes_pushed);
+
+    SUCCESS
+}
+
+// Reusable scratch holding the writers resolved for a single frame push, so a
+// DAQ loop pushing the same channel set every scan never reallocates it.
+thread_local! {
+    static FRAME_SCRATCH: std::cell::RefCell<Vec<Arc<Mutex<WriterState>>>> =
+        std::cell::RefCell::new(Vec::new());
+}
+
+/// Resolve a frame's writer handles against the registry into `out`,
+/// rejecting any channel whose value pointer is null along the way. Writes
+/// into the caller's (reused) `out` buffer instead of returning a fresh
+/// `Vec`, so a steady-state DAQ loop calling this every frame does not
+/// allocate. Generic over the registry's value type so this validation
+/// logic — duplicate handles (accepted), unknown handles, per-channel null
+/// rejection —
+/// is unit-testable without a real `WriterState`/`NominalDatasetStream`.
+fn resolve_frame_handles<V: Clone>(
+    registry: &HashMap<WriterHandle, V>,
+    handles: &[u64],
+    value_ptrs: &[*const f64],
+    out: &mut Vec<V>,
+) -> Result<(), c_int> {
+    out.clear();
+    for (c, &handle) in handles.iter().enumerate() {
+        if value_ptrs[c].is_null() {
+            set_last_error(format!("Null value array for channel {}", c));
+            return Err(ERROR_INVALID_PARAM);
+        }
+        match registry.get(&handle) {
+            Some(v) => out.push(v.clone()),
+            None => {
+                set_last_error(format!("Invalid writer handle: {}", handle));
+                return Err(ERROR_INVALID_HANDLE);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Push one timestamp vector across many channels in a single FFI call.
+///
+/// `writer_handles` and `values` are parallel arrays of length `channel_count`;
+/// each `values[c]` points at `sample_count` doubles sharing the single
+/// `timestamps_ns` vector. The writer registry is locked once for the whole
+/// frame rather than once per channel, which is the point of the call for
+/// high-rate acquisition loops.
+#[no_mangle]
+pub unsafe extern "C" fn nominal_push_frame(
+    writer_handles: *const u64,
+    values: *const *const f64,
+    timestamps_ns: *const u64,
+    sample_count: usize,
+    channel_count: usize,
+) -> c_int {
+    clear_last_error();
+
+    if writer_handles.is_null() || values.is_null() || timestamps_ns.is_null() {
+        set_last_error("Null pointer provided for frame arrays".to_string());
+        return ERROR_INVALID_PARAM;
+    }
+
+    if channel_count == 0 || sample_count == 0 {
+        return SUCCESS;
+    }
+
+    let handles_slice = std::slice::from_raw_parts(writer_handles, channel_count);
+    let value_ptrs_slice = std::slice::from_raw_parts(values, channel_count);
+    let timestamps_slice = std::slice::from_raw_parts(timestamps_ns, sample_count);
+
+    // Resolve every writer under one registry lock, directly into the reused
+    // scratch Vec so a steady-state frame push never allocates.
+    let resolved = FRAME_SCRATCH.with(|scratch| {
+        let mut scratch = scratch.borrow_mut();
+        let writers = WRITERS.lock();
+        resolve_frame_handles(&writers, handles_slice, value_ptrs_slice, &mut scratch)
+    });
+
+    if let Err(code) = resolved {
+        FRAME_SCRATCH.with(|scratch| scratch.borrow_mut().clear());
+        return code;
+    }
+
+    FRAME_SCRATCH.with(|scratch| {
+        let scratch = scratch.borrow();
+        for (c, writer_arc) in scratch.iter().enumerate() {
+            let value_slice = std::slice::from_raw_parts(value_ptrs_slice[c], sample_count);
+            let state_guard = writer_arc.lock();
+            let mut writer = state_guard.stream.double_writer(&state_guard.descriptor);
+            for j in 0..sample_count {
+                writer.push(Duration::from_nanos(timestamps_slice[j]), value_slice[j]);
+            }
+            record_push(
+                &state_guard,
+                sample_count as u64,
+                (sample_count * std::mem::size_of::<f64>()) as u64,
+            );
+        }
+    });
+
+    FRAME_SCRATCH.with(|scratch| scratch.borrow_mut().clear());
     SUCCESS
 }
 
@@ -751,6 +1311,60 @@ mod tests {
         assert_eq!(tags.len(), 0);
     }
 
+    #[test]
+    fn test_error_dispatch_delivers_to_callback() {
+        use std::sync::atomic::AtomicI32;
+
+        static RECEIVED_CODE: AtomicI32 = AtomicI32::new(0);
+
+        extern "C" fn record(code: c_int, message: *const c_char, user_data: *mut c_void) {
+            let msg = unsafe { CStr::from_ptr(message) }.to_str().unwrap();
+            assert_eq!(msg, "boom");
+            assert!(!user_data.is_null());
+            RECEIVED_CODE.store(code, Ordering::SeqCst);
+        }
+
+        let mut sentinel = 0u8;
+        let cb = ErrorCallback {
+            func: record,
+            user_data: &mut sentinel as *mut u8 as *mut c_void,
+        };
+
+        let (sender, receiver) = mpsc::channel();
+        let dispatch_thread =
+            spawn_error_dispatch_thread(cb, receiver).expect("spawn dispatch thread");
+
+        sender.send((ERROR_RUNTIME, "boom".to_string())).unwrap();
+        // Dropping the sender closes the channel, which ends the dispatch
+        // thread's recv() loop; joining proves it actually delivered the
+        // message (and exited) rather than leaking.
+        drop(sender);
+        dispatch_thread.join().unwrap();
+
+        assert_eq!(RECEIVED_CODE.load(Ordering::SeqCst), ERROR_RUNTIME);
+    }
+
+    #[test]
+    fn test_writer_backlog() {
+        // Nothing flushed yet: everything pushed is still outstanding.
+        assert_eq!(writer_backlog(100, 0, 800, 0), (800, 100));
+        // A flush catches the counters up; backlog reflects only what was
+        // pushed since.
+        assert_eq!(writer_backlog(150, 100, 1200, 800), (400, 50));
+        // Fully caught up: no backlog.
+        assert_eq!(writer_backlog(150, 150, 1200, 1200), (0, 0));
+    }
+
+    #[test]
+    fn test_clamp_fd_target() {
+        // Without a ceiling the desired value passes through unchanged.
+        assert_eq!(clamp_fd_target(8192, None), 8192);
+        // A ceiling below the desired value clamps it down.
+        assert_eq!(clamp_fd_target(8192, Some(1024)), 1024);
+        // A ceiling above the desired value leaves it untouched.
+        assert_eq!(clamp_fd_target(512, Some(1024)), 512);
+    }
+
     #[test]
     fn test_handle_allocation() {
         let h1 = allocate_stream_handle();
@@ -758,4 +1372,40 @@ mod tests {
         assert_ne!(h1, h2);
         assert!(h2 > h1);
     }
+
+    #[test]
+    fn test_resolve_frame_handles() {
+        let mut registry = HashMap::new();
+        registry.insert(1u64, "writer-a");
+        registry.insert(2u64, "writer-b");
+
+        let a = 1.0f64;
+        let b = 2.0f64;
+        let ptrs = [&a as *const f64, &b as *const f64];
+        let mut out = Vec::new();
+
+        // Valid handles resolve in order; the same handle may appear more
+        // than once (a channel written from two value arrays).
+        resolve_frame_handles(&registry, &[1, 1, 2], &[ptrs[0], ptrs[0], ptrs[1]], &mut out)
+            .unwrap();
+        assert_eq!(out, vec!["writer-a", "writer-a", "writer-b"]);
+
+        // A second call reuses (and fully overwrites) the same `out` buffer
+        // without the caller reallocating it — the behavior the hot path
+        // depends on to stay allocation-free.
+        let out_ptr_before = out.as_ptr();
+        resolve_frame_handles(&registry, &[2], &[ptrs[1]], &mut out).unwrap();
+        assert_eq!(out, vec!["writer-b"]);
+        assert_eq!(out.as_ptr(), out_ptr_before);
+
+        // An unknown handle is rejected.
+        let err = resolve_frame_handles(&registry, &[1, 99], &ptrs, &mut out).unwrap_err();
+        assert_eq!(err, ERROR_INVALID_HANDLE);
+
+        // A null value pointer for a channel is rejected before the handle is
+        // even looked up.
+        let null_ptrs = [ptrs[0], std::ptr::null()];
+        let err = resolve_frame_handles(&registry, &[1, 2], &null_ptrs, &mut out).unwrap_err();
+        assert_eq!(err, ERROR_INVALID_PARAM);
+    }
 }
\ No newline at end of file